@@ -1,7 +1,11 @@
 //! Raw API types.
 
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+
+/// Identifier of a game, as used by the Freestuff API.
+pub type GameId = u64;
 
 /// Service status
 #[derive(Debug, Deserialize)]
@@ -61,6 +65,68 @@ pub struct GameInfo {
     pub localized: Option<HashMap<String, LocalizedGameInfo>>,
 }
 
+impl GameInfo {
+    /// Look up localized info for a language code, case-insensitively.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use freestuffapi::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().key("secret api key").build()?;
+    /// let game = client.game_detail(1234).await?;
+    /// if let Some(de) = game.localized_for("de") {
+    ///     println!("{}", de.claim_long);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn localized_for(&self, lang: &str) -> Option<&LocalizedGameInfo> {
+        self.localized
+            .as_ref()?
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(lang))
+            .map(|(_, info)| info)
+    }
+
+    /// Look up localized info, walking `preferred` in order and falling
+    /// back to the localized `"en"` entry if none match.
+    ///
+    /// Returns `None` only if `localized` is absent entirely, or contains
+    /// neither a preferred language nor `"en"` — callers still wanting a
+    /// last-resort fallback can fall back further to [`GameInfo::title`]
+    /// and friends, as shown below.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use freestuffapi::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().key("secret api key").build()?;
+    /// let game = client.game_detail(1234).await?;
+    /// let claim = game
+    ///     .localized_best(&["de"])
+    ///     .map(|info| info.claim_long.as_str())
+    ///     .unwrap_or(&game.title);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn localized_best(&self, preferred: &[&str]) -> Option<&LocalizedGameInfo> {
+        preferred
+            .iter()
+            .find_map(|lang| self.localized_for(lang))
+            .or_else(|| self.localized_for("en"))
+    }
+
+    /// Language codes with localized info available.
+    pub fn localized_languages(&self) -> impl Iterator<Item = &str> {
+        self.localized
+            .iter()
+            .flatten()
+            .map(|(code, _)| code.as_str())
+    }
+}
+
 /// Game URLs
 #[derive(Debug, Deserialize)]
 pub struct Urls {
@@ -171,8 +237,48 @@ pub enum AnnouncementType {
     Unknown(String),
 }
 
+/// A single bit in a [`GameFlags`] set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameFlag {
+    /// Low quality game
+    Trash,
+    /// Third party key provider
+    ThirdParty,
+    /// Bit not yet documented by the API
+    Reserved(u8),
+}
+
+impl GameFlag {
+    fn from_bit(bit: u8) -> Self {
+        match bit {
+            0 => GameFlag::Trash,
+            1 => GameFlag::ThirdParty,
+            bit => GameFlag::Reserved(bit),
+        }
+    }
+
+    fn bit(self) -> u8 {
+        match self {
+            GameFlag::Trash => 0,
+            GameFlag::ThirdParty => 1,
+            GameFlag::Reserved(bit) => bit,
+        }
+    }
+}
+
+impl fmt::Display for GameFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameFlag::Trash => write!(f, "trash"),
+            GameFlag::ThirdParty => write!(f, "thirdparty"),
+            GameFlag::Reserved(bit) => write!(f, "reserved({bit})"),
+        }
+    }
+}
+
 /// Game flags
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct GameFlags(u8);
 
 impl GameFlags {
@@ -181,18 +287,34 @@ impl GameFlags {
         self.0
     }
 
-    fn bit(&self, bit: usize) -> bool {
-        self.0 >> bit & 1 == 1
+    /// Whether `flag` is set.
+    ///
+    /// `GameFlag::Reserved` can name a bit beyond this type's width (it
+    /// wraps a bare `u8`); such a flag is simply never set.
+    pub fn contains(&self, flag: GameFlag) -> bool {
+        self.0
+            .checked_shr(flag.bit() as u32)
+            .is_some_and(|shifted| shifted & 1 == 1)
     }
 
-    /// Low quality game
-    pub fn trash(&self) -> bool {
-        self.bit(0)
+    /// Iterate over the flags that are set, low bit first.
+    pub fn iter(&self) -> impl Iterator<Item = GameFlag> + '_ {
+        (0..u8::BITS as u8)
+            .filter(move |bit| self.0 >> bit & 1 == 1)
+            .map(GameFlag::from_bit)
     }
+}
 
-    /// Third party key provider
-    pub fn thirdparty(&self) -> bool {
-        self.bit(1)
+impl fmt::Display for GameFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut flags = self.iter();
+        if let Some(flag) = flags.next() {
+            write!(f, "{flag}")?;
+            for flag in flags {
+                write!(f, ", {flag}")?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -18,16 +18,44 @@
 //! # }
 //! ```
 
-// @TODO: escape input! like category and game ids
-
 use crate::api::*;
+use futures::stream::{self, Stream, StreamExt};
+use percent_encoding::{AsciiSet, CONTROLS};
 use reqwest::{header, Client as RClient, Method, Response, StatusCode, Url};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 
+/// Base delay used for exponential backoff when a ratelimited response
+/// carries no `Retry-After` header.
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+/// Upper bound on any backoff delay, whether from `Retry-After` or
+/// exponential backoff.
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+/// Characters percent-encoded in a path segment.
+///
+/// Stricter than [`Url::path_segments_mut`]'s own encoding, which leaves
+/// `'` untouched — we escape it too so user input (search queries, ...)
+/// round-trips the same way it does for other Freestuff clients, e.g.
+/// `Assassin's Creed III` becomes `Assassin%27s%20Creed%20III`.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'?')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%')
+    .add(b'\\')
+    .add(b'\'');
+
 type APIError = String;
-type GameId = u64;
 
 /// Builder errors
 #[derive(Error, Debug)]
@@ -45,6 +73,8 @@ pub enum BuilderError {
 pub struct Builder {
     api_domain: Url,
     api_key: Option<String>,
+    max_retries: u32,
+    respect_retry_after: bool,
 }
 
 impl Builder {
@@ -67,6 +97,8 @@ impl Builder {
                 .parse()
                 .expect("Failed to parse default API base URL"),
             api_key: None,
+            max_retries: 0,
+            respect_retry_after: true,
         }
     }
 
@@ -84,6 +116,27 @@ impl Builder {
         self
     }
 
+    /// Set the maximum number of times a ratelimited request is retried.
+    ///
+    /// Defaults to `0`, meaning a `429` is immediately surfaced as
+    /// [`ClientError::Ratelimited`]. When set, the client backs off and
+    /// re-issues the request instead, see [`Builder::respect_retry_after`].
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Whether to honor the `Retry-After` header when backing off from a
+    /// ratelimit. Defaults to `true`.
+    ///
+    /// When `false`, or when a ratelimited response omits the header, an
+    /// exponential backoff is used instead (doubling from 500ms, capped at
+    /// 30s).
+    pub fn respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
     /// Consume Builder and construct Client
     pub fn build(self) -> Result<Client, BuilderError> {
         let api_key = self.api_key.ok_or(BuilderError::NoAPIKey)?;
@@ -97,6 +150,8 @@ impl Builder {
             api_domain: self.api_domain,
             api_key,
             http_client,
+            max_retries: self.max_retries,
+            respect_retry_after: self.respect_retry_after,
         })
     }
 }
@@ -128,6 +183,8 @@ pub struct Client {
     api_domain: Url,
     api_key: String,
     http_client: RClient,
+    max_retries: u32,
+    respect_retry_after: bool,
 }
 
 impl Client {
@@ -138,42 +195,78 @@ impl Client {
         Builder::new()
     }
 
-    /// Build API endpoint URL
-    fn api_endpoint(&self, endpoint: &str) -> Url {
-        self.api_domain
-            .join(endpoint)
-            .expect("Failed to construct API endpoint URL")
+    /// Build API endpoint URL from individual path segments.
+    ///
+    /// Each segment is percent-encoded against [`PATH_SEGMENT`], so
+    /// user-supplied input (category names, game IDs, search queries, ...)
+    /// can't break the request or escape the path via `/`.
+    fn api_endpoint(&self, segments: &[&str]) -> Url {
+        let mut url = self.api_domain.clone();
+        // Built by hand: `path_segments_mut` would encode the `%` of our
+        // escapes a second time.
+        let mut path = url.path().trim_end_matches('/').to_string();
+        for segment in segments {
+            // `set_path` would resolve these as relative path components.
+            if matches!(*segment, "." | "..") {
+                continue;
+            }
+            path.push('/');
+            path.extend(percent_encoding::utf8_percent_encode(segment, PATH_SEGMENT));
+        }
+        url.set_path(&path);
+        url
     }
 
     /// Send authorized requests to API
+    ///
+    /// Transparently retries ratelimited requests up to the
+    /// [`Builder::max_retries`] limit, backing off per
+    /// [`Builder::respect_retry_after`] before surfacing
+    /// [`ClientError::Ratelimited`].
     async fn send_request(
         &self,
-        endpoint: &str,
+        segments: &[&str],
         _parameters: Option<()>,
     ) -> ClientResult<Response> {
-        let url = self.api_endpoint(endpoint);
-        let request = self
-            .http_client
-            .request(Method::GET, url)
-            .header(header::AUTHORIZATION, format!("Basic {}", self.api_key))
-            .build()
-            .map_err(ClientError::HTTP)?;
+        let url = self.api_endpoint(segments);
 
-        self.http_client
-            .execute(request)
-            .await
-            .map_err(ClientError::HTTP)
-            .and_then(|response| match response.status() {
-                status if status.is_success() => Ok(response),
-                StatusCode::TOO_MANY_REQUESTS => Err(ClientError::Ratelimited),
-                _ => Err(ClientError::InvalidResponse),
-            })
+        let mut attempt = 0;
+        loop {
+            let request = self
+                .http_client
+                .request(Method::GET, url.clone())
+                .header(header::AUTHORIZATION, format!("Basic {}", self.api_key))
+                .build()
+                .map_err(ClientError::HTTP)?;
+
+            let response = self
+                .http_client
+                .execute(request)
+                .await
+                .map_err(ClientError::HTTP)?;
+
+            match response.status() {
+                status if status.is_success() => return Ok(response),
+                StatusCode::TOO_MANY_REQUESTS if attempt < self.max_retries => {
+                    let delay = self
+                        .respect_retry_after
+                        .then(|| retry_after_delay(&response))
+                        .flatten()
+                        .unwrap_or_else(|| backoff_delay(attempt));
+
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                StatusCode::TOO_MANY_REQUESTS => return Err(ClientError::Ratelimited),
+                _ => return Err(ClientError::InvalidResponse),
+            }
+        }
     }
 
     /// Pings via API and returns if success
     pub async fn ping(&self) -> ClientResult<bool> {
         Ok(self
-            .send_request("/v1/ping", None)
+            .send_request(&["v1", "ping"], None)
             .await?
             .status()
             .is_success())
@@ -183,8 +276,7 @@ impl Client {
     ///
     /// Valid categories are `all`, `approved`, and `free`.
     pub async fn game_list(&self, category: &str) -> ClientResult<Vec<GameId>> {
-        let path = format!("/v1/games/{category}");
-        self.send_request(&path, None)
+        self.send_request(&["v1", "games", category], None)
             .await?
             .json::<ApiResponse<Vec<GameId>>>()
             .await
@@ -220,8 +312,7 @@ impl Client {
             .reduce(|acc, id| format!("{acc}+{id}"))
             .expect("at least one id must be specified");
 
-        let path = format!("/v1/game/{ids}/info");
-        self.send_request(&path, None)
+        self.send_request(&["v1", "game", ids.as_str(), "info"], None)
             .await?
             .json::<ApiResponse<HashMap<String, GameInfo>>>()
             .await
@@ -240,6 +331,122 @@ impl Client {
             .await
             .and_then(|map| map.into_values().next().ok_or(ClientError::InvalidResponse))
     }
+
+    /// Search for games by title.
+    ///
+    /// Returns the IDs of matching games, which can be hydrated with
+    /// [`game_details`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use freestuffapi::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().key("secret api key").build()?;
+    /// let ids = client.search("Assassin's Creed III").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`game_details`]: Self::game_details
+    pub async fn search(&self, query: &str) -> ClientResult<Vec<GameId>> {
+        self.send_request(&["v1", "search", query], None)
+            .await?
+            .json::<ApiResponse<Vec<GameId>>>()
+            .await
+            .map_err(ClientError::HTTP)?
+            .into_data()
+            .map_err(ClientError::API)
+    }
+
+    /// Stream info for many games, respecting the five-per-request cap.
+    ///
+    /// Internally chunks `ids` into batches of five and issues one
+    /// [`game_details`] call per batch, yielding each batch's games as
+    /// soon as they're ready rather than waiting on every batch. Up to
+    /// `concurrency` batches are requested in flight at once, in
+    /// submission order — a later batch that resolves first still waits
+    /// behind earlier ones, same as [`StreamExt::buffered`] generally. A
+    /// `concurrency` of `0` is treated as `1` rather than stalling the
+    /// stream. A throttled batch is retried by [`send_request`] up to
+    /// [`Builder::max_retries`] times (none by default); a batch that
+    /// still fails yields its error as an item and the stream goes on.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use freestuffapi::Client;
+    /// # use futures::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().key("secret api key").build()?;
+    /// let ids = client.game_list("free").await?;
+    /// client
+    ///     .game_details_stream(&ids, 4)
+    ///     .for_each(|result| async move {
+    ///         if let Ok((_, info)) = result {
+    ///             println!("{}", info.title);
+    ///         }
+    ///     })
+    ///     .await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`game_details`]: Self::game_details
+    /// [`send_request`]: Self::send_request
+    pub fn game_details_stream<'a>(
+        &'a self,
+        ids: &'a [GameId],
+        concurrency: usize,
+    ) -> impl Stream<Item = ClientResult<(GameId, GameInfo)>> + 'a {
+        stream::iter(ids.chunks(5))
+            .map(move |chunk| self.game_details(chunk))
+            .buffered(clamp_concurrency(concurrency))
+            .flat_map(|result| {
+                let items: Vec<ClientResult<(GameId, GameInfo)>> = match result {
+                    Ok(map) => map
+                        .into_iter()
+                        .map(|(id, info)| {
+                            id.parse::<GameId>()
+                                .map(|id| (id, info))
+                                .map_err(|_| ClientError::InvalidResponse)
+                        })
+                        .collect(),
+                    Err(err) => vec![Err(err)],
+                };
+                stream::iter(items)
+            })
+    }
+}
+
+/// Exponential backoff delay for a given (0-indexed) retry attempt,
+/// doubling from [`BACKOFF_BASE`] and capped at [`BACKOFF_MAX`].
+fn backoff_delay(attempt: u32) -> Duration {
+    BACKOFF_BASE
+        .checked_mul(1 << attempt.min(16))
+        .map_or(BACKOFF_MAX, |delay| delay.min(BACKOFF_MAX))
+}
+
+/// Parse a ratelimited response's `Retry-After` header, as either a
+/// number of seconds or an HTTP-date, into a delay to wait before
+/// retrying. Capped at [`BACKOFF_MAX`], same as the exponential backoff,
+/// so a misbehaving server can't stall a poller for hours.
+fn retry_after_delay(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds).min(BACKOFF_MAX));
+    }
+
+    let at = httpdate::parse_http_date(value).ok()?;
+    Some(at.duration_since(SystemTime::now()).unwrap_or_default().min(BACKOFF_MAX))
+}
+
+/// Clamp a caller-supplied `concurrency` to a value [`StreamExt::buffered`]
+/// can make progress with. `buffered(0)` never polls its inner futures, so
+/// a `concurrency` of `0` is treated as `1` instead of stalling the stream.
+fn clamp_concurrency(concurrency: usize) -> usize {
+    concurrency.max(1)
 }
 
 #[derive(Debug, Deserialize)]
@@ -257,3 +464,26 @@ impl<Data> ApiResponse<Data> {
         self.message.map(Err).unwrap_or(Ok(self.data))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_concurrency_treats_zero_as_one() {
+        assert_eq!(clamp_concurrency(0), 1);
+        assert_eq!(clamp_concurrency(1), 1);
+        assert_eq!(clamp_concurrency(8), 8);
+    }
+
+    #[test]
+    fn api_endpoint_percent_encodes_path_segments() {
+        let client = Client::builder().key("secret api key").build().unwrap();
+
+        let url = client.api_endpoint(&["v1", "search", "Assassin's Creed III"]);
+        assert_eq!(url.path(), "/v1/search/Assassin%27s%20Creed%20III");
+
+        let url = client.api_endpoint(&["v1", "games", "a/b"]);
+        assert_eq!(url.path(), "/v1/games/a%2Fb");
+    }
+}
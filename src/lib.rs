@@ -26,3 +26,5 @@ pub mod api;
 pub mod client;
 #[cfg(feature = "client")]
 pub use client::Client;
+#[cfg(feature = "webhook")]
+pub mod webhook;
@@ -0,0 +1,120 @@
+//! Inbound webhook handling.
+//!
+//! In addition to pull-style polling, Freestuff can push new free-game
+//! announcements to a webhook URL you register with them. This module
+//! parses those inbound POST bodies and verifies the shared-secret
+//! signature header, without depending on any particular web framework
+//! (axum, actix, ...) — wire [`parse_event`] and [`verify_signature`] into
+//! whichever handler you already have.
+//!
+//! # Example
+//! ```no_run
+//! # use freestuffapi::webhook::{parse_event, verify_signature, Event};
+//! # fn handle(body: &[u8], signature: &str, secret: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! if !verify_signature(signature, secret) {
+//!     return Err("invalid webhook signature".into());
+//! }
+//!
+//! match parse_event(body)? {
+//!     Event::FreeGames(ids) => {
+//!         // hydrate via `Client::game_details(&ids)`
+//!     }
+//!     Event::Ping => {}
+//! }
+//! #     Ok(())
+//! # }
+//! ```
+
+use crate::api::GameId;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Webhook errors
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    /// Payload could not be parsed as a webhook event
+    #[error("Failed to parse webhook payload")]
+    InvalidPayload(#[from] serde_json::Error),
+}
+
+/// An event sent to a registered webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// New free games were announced.
+    ///
+    /// Hydrate these with [`Client::game_details`].
+    ///
+    /// [`Client::game_details`]: crate::client::Client::game_details
+    FreeGames(Vec<GameId>),
+
+    /// Test event sent to confirm the webhook is reachable.
+    Ping,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum RawEvent {
+    FreeGames { data: Vec<GameId> },
+    Ping,
+}
+
+/// Parse an inbound webhook POST body into an [`Event`].
+pub fn parse_event(body: &[u8]) -> Result<Event, WebhookError> {
+    let raw: RawEvent = serde_json::from_slice(body)?;
+    Ok(match raw {
+        RawEvent::FreeGames { data } => Event::FreeGames(data),
+        RawEvent::Ping => Event::Ping,
+    })
+}
+
+/// Verify a webhook signature header against the configured shared secret.
+///
+/// Freestuff signs webhook requests by sending the shared secret you
+/// configured when registering the webhook in a header (e.g.
+/// `X-Freestuff-Signature`). Extract that header yourself and pass its
+/// value here, before calling [`parse_event`] on the body. Comparison is
+/// constant-time to avoid leaking the secret through timing side-channels.
+pub fn verify_signature(received: &str, secret: &str) -> bool {
+    constant_time_eq(received.as_bytes(), secret.as_bytes())
+}
+
+/// Constant-time byte-slice equality.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_free_games_event() {
+        let body = br#"{"event":"free_games","data":[1234,5678]}"#;
+        let event = parse_event(body).unwrap();
+        assert_eq!(event, Event::FreeGames(vec![1234, 5678]));
+    }
+
+    #[test]
+    fn parses_ping_event() {
+        let body = br#"{"event":"ping"}"#;
+        let event = parse_event(body).unwrap();
+        assert_eq!(event, Event::Ping);
+    }
+
+    #[test]
+    fn rejects_unknown_event() {
+        let body = br#"{"event":"something_else"}"#;
+        assert!(parse_event(body).is_err());
+    }
+
+    #[test]
+    fn verifies_matching_signature() {
+        assert!(verify_signature("top-secret", "top-secret"));
+        assert!(!verify_signature("top-secret", "wrong"));
+        assert!(!verify_signature("short", "longer-secret"));
+    }
+}